@@ -0,0 +1,61 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// A row index into a grid, analogous to the line index used by terminal grids.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Row(pub usize);
+
+/// A column index into a grid, analogous to the column index used by terminal grids.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Col(pub usize);
+
+impl Add for Row {
+    type Output = Row;
+    fn add(self, rhs: Row) -> Row {
+        Row(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Row {
+    type Output = Row;
+    fn sub(self, rhs: Row) -> Row {
+        Row(self.0 - rhs.0)
+    }
+}
+
+impl AddAssign for Row {
+    fn add_assign(&mut self, rhs: Row) {
+        self.0 += rhs.0;
+    }
+}
+
+impl SubAssign for Row {
+    fn sub_assign(&mut self, rhs: Row) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Add for Col {
+    type Output = Col;
+    fn add(self, rhs: Col) -> Col {
+        Col(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Col {
+    type Output = Col;
+    fn sub(self, rhs: Col) -> Col {
+        Col(self.0 - rhs.0)
+    }
+}
+
+impl AddAssign for Col {
+    fn add_assign(&mut self, rhs: Col) {
+        self.0 += rhs.0;
+    }
+}
+
+impl SubAssign for Col {
+    fn sub_assign(&mut self, rhs: Col) {
+        self.0 -= rhs.0;
+    }
+}
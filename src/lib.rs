@@ -44,12 +44,16 @@ assert_eq!(control, test);
 ```
 */
 
+mod coord;
 mod point;
+mod sparse;
 mod x;
 mod y;
 
 pub use mode::*;
 #[doc(inline)]
+pub use coord::{Col, Row};
+#[doc(inline)]
 pub use point::Point;
 #[doc(inline)]
 pub use x::X;
@@ -57,15 +61,30 @@ pub use x::X;
 pub use y::Y;
 
 mod mode {
+    use crate::{sparse::SparseStorage, Point};
+
     /// The mode of the grid.
-    pub trait Mode: Default + Copy + Clone + PartialEq + Eq {}
+    pub trait Mode: Default + Copy + Clone + PartialEq + Eq {
+        /// The backing storage used by [`super::X`] for this mode.
+        type Storage<T>;
+    }
 
     #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
     /// Denotes a symetrical grid.
     pub struct Symetrical;
-    impl Mode for Symetrical {}
+    impl Mode for Symetrical {
+        type Storage<T> = Vec<Point<T>>;
+    }
     #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
     /// Denotes a asymetrical grid.
     pub struct Asymetrical;
-    impl Mode for Asymetrical {}
+    impl Mode for Asymetrical {
+        type Storage<T> = Vec<Point<T>>;
+    }
+    #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+    /// Denotes a sparse grid, which only stores the cells that have been set.
+    pub struct Sparse;
+    impl Mode for Sparse {
+        type Storage<T> = SparseStorage<T>;
+    }
 }
@@ -0,0 +1,60 @@
+use super::Point;
+
+/** Compressed sparse row storage backing [`super::X<T, super::Sparse>`].
+
+Only cells that have been set are kept (a cell set to `T::default()` is kept too): each row's entries are stored contiguously, sorted by column, in
+`cols`/`values`, and `offsets[row]..offsets[row + 1]` slices out that row's entries (classic CSR).
+`offsets` always has `rows + 1` entries, so an empty grid starts as `offsets: vec![0]`.
+*/
+#[derive(Debug, Clone)]
+pub struct SparseStorage<T> {
+    pub(crate) offsets: Vec<usize>,
+    pub(crate) cols: Vec<usize>,
+    pub(crate) values: Vec<Point<T>>,
+}
+
+impl<T> SparseStorage<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            offsets: vec![0],
+            cols: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Appends a new, empty row.
+    pub(crate) fn push_row(&mut self) {
+        self.offsets.push(*self.offsets.last().unwrap());
+    }
+
+    /// Returns the stored `(col, value)` entries belonging to `row`, sorted by column.
+    fn row(&self, row: usize) -> (&[usize], &[Point<T>]) {
+        let start = self.offsets[row];
+        let end = self.offsets[row + 1];
+        (&self.cols[start..end], &self.values[start..end])
+    }
+
+    /// Looks up `(row, col)`, returning a reference to its stored value if it isn't a miss.
+    pub(crate) fn get(&self, row: usize, col: usize) -> Option<&T> {
+        let (cols, values) = self.row(row);
+        cols.binary_search(&col)
+            .ok()
+            .map(|i| &values[i].0)
+    }
+
+    /// Inserts or updates `(row, col)`, keeping the row's entries sorted by column.
+    pub(crate) fn insert(&mut self, row: usize, col: usize, value: T) {
+        let start = self.offsets[row];
+        let end = self.offsets[row + 1];
+        match self.cols[start..end].binary_search(&col) {
+            Ok(i) => self.values[start + i] = value.into(),
+            Err(i) => {
+                self.cols.insert(start + i, col);
+                self.values.insert(start + i, value.into());
+                for offset in &mut self.offsets[row + 1..] {
+                    *offset += 1;
+                }
+            }
+        }
+    }
+}
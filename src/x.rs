@@ -1,19 +1,67 @@
-use super::{Point, Y};
+use super::{sparse::SparseStorage, Col, Mode, Point, Row, Sparse, Symetrical, Y};
 use std::{
-    ops::{Deref, DerefMut},
+    fmt,
+    marker::PhantomData,
+    ops::{
+        Add, AddAssign, Deref, DerefMut, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub,
+        SubAssign,
+    },
     slice,
 };
-#[derive(Debug, Clone)]
-/// A representation of a 2D data structure.
-#[repr(transparent)]
-pub struct X<T>(pub(crate) Vec<Y<T>>);
+
+/** A representation of a 2D data structure.
+
+In the default [`Symetrical`] mode, cells are stored in a single contiguous, row-major
+`Vec<Point<T>>` rather than a `Vec` of rows, so a full-grid scan (or the neighbor/step helpers below)
+walks one flat allocation instead of chasing a pointer per row. The `M` parameter selects the backing
+[`Mode::Storage`]; it defaults to [`Symetrical`]. Apart from `Default`, `get_point` and `push_point`
+on `X<T, Sparse>`, the methods and operators below are implemented for the `Symetrical` mode only.
+*/
+pub struct X<T, M: Mode = Symetrical> {
+    pub(crate) storage: M::Storage<T>,
+    pub(crate) rows: usize,
+    pub(crate) cols: usize,
+    pub(crate) mode: PhantomData<M>,
+}
+
+impl<T, M: Mode> fmt::Debug for X<T, M>
+where
+    M::Storage<T>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("X")
+            .field("storage", &self.storage)
+            .field("rows", &self.rows)
+            .field("cols", &self.cols)
+            .finish()
+    }
+}
+
+impl<T, M: Mode> Clone for X<T, M>
+where
+    M::Storage<T>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            storage: self.storage.clone(),
+            rows: self.rows,
+            cols: self.cols,
+            mode: PhantomData,
+        }
+    }
+}
 
 impl<T> Default for X<T>
 where
     T: Default + Clone + Into<Point<T>>,
 {
     fn default() -> Self {
-        Self(Vec::new())
+        Self {
+            storage: Vec::new(),
+            rows: 0,
+            cols: 0,
+            mode: PhantomData,
+        }
     }
 }
 
@@ -23,7 +71,88 @@ where
 {
     /// Returns a new instance of Self
     pub fn new() -> Self {
-        Self(Vec::new())
+        Self {
+            storage: Vec::new(),
+            rows: 0,
+            cols: 0,
+            mode: PhantomData,
+        }
+    }
+
+    /// Returns the number of rows in the grid.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns `true` if the grid has no rows.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.rows == 0
+    }
+
+    #[inline]
+    fn to_index(&self, x: usize, y: usize) -> usize {
+        x * self.cols + y
+    }
+
+    /** Converts `(x, y)` into its linear index into the grid's flat, row-major storage.
+    # Examples
+    ```
+    use symetrical_grid::grid;
+
+    let grid = grid![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    assert_eq!(grid.to_linear(1, 1), 4);
+    ```
+    */
+    #[inline]
+    pub fn to_linear(&self, x: usize, y: usize) -> usize {
+        self.to_index(x, y)
+    }
+
+    /** Converts a linear index back into its `(row, col)` coordinates.
+
+    On an empty grid (`cols == 0`) there is no valid `(row, col)` to divide `idx` into, so this
+    returns `(idx, 0)` rather than panicking.
+    # Examples
+    ```
+    use symetrical_grid::grid;
+
+    let grid = grid![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    assert_eq!(grid.from_linear(4), (1, 1));
+    ```
+    */
+    #[inline]
+    pub fn from_linear(&self, idx: usize) -> (usize, usize) {
+        if self.cols == 0 {
+            return (idx, 0);
+        }
+        (idx / self.cols, idx % self.cols)
+    }
+
+    /** Offsets `(x, y)` by `delta` cells, wrapping across row boundaries the way a terminal cursor
+    advances through a line buffer.
+
+    Treats the grid as a single sequence of `rows * cols` cells: the signed target position
+    `x * cols + y + delta` is mapped back to `(row, col)` via [`X::from_linear`], or `None` if it
+    falls outside `[0, rows * cols)`.
+    # Examples
+    ```
+    use symetrical_grid::grid;
+
+    let grid = grid![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    assert_eq!(grid.offset(0, 2, 1), Some((1, 0)));
+    assert_eq!(grid.offset(1, 0, -1), Some((0, 2)));
+    assert_eq!(grid.offset(0, 0, -1), None);
+    assert_eq!(grid.offset(2, 2, 1), None);
+    ```
+    */
+    pub fn offset(&self, x: usize, y: usize, delta: isize) -> Option<(usize, usize)> {
+        let linear = self.to_index(x, y) as isize + delta;
+        if linear < 0 || linear as usize >= self.rows * self.cols {
+            return None;
+        }
+        Some(self.from_linear(linear as usize))
     }
 
     /** Returns a reference to the value at point.
@@ -37,14 +166,10 @@ where
     ```
     */
     pub fn get_point(&self, x: usize, y: usize) -> Option<&T> {
-        if x >= self.len() {
-            return None;
-        }
-
-        if y >= self[x].len() {
+        if x >= self.rows || y >= self.cols {
             return None;
         }
-        Some(&self[x][y].0)
+        Some(&self.storage[self.to_index(x, y)].0)
     }
 
     /** Returns a mutable reference to the value at point.
@@ -58,14 +183,11 @@ where
     ```
     */
     pub fn get_point_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
-        if x >= self.len() {
+        if x >= self.rows || y >= self.cols {
             return None;
         }
-
-        if y >= self[x].len() {
-            return None;
-        }
-        Some(&mut self[x][y].0)
+        let idx = self.to_index(x, y);
+        Some(&mut self.storage[idx].0)
     }
 
     /** Returns an iterator visiting all values in each row.
@@ -82,15 +204,7 @@ where
     ```
     */
     pub fn iter<'a>(&'a self) -> Iter<'a, T> {
-        let mut iter = self.0.iter();
-        let column: slice::Iter<Point<T>> = match iter.next() {
-            Some(column) => column.iter(),
-            None => [].iter(),
-        };
-        Iter {
-            rows: iter,
-            colums: column,
-        }
+        Iter(self.storage.iter())
     }
     /** Provides a forward iterator with mutable references.
     # Examples
@@ -107,22 +221,56 @@ where
     ```
     */
     pub fn iter_mut<'a>(&'a mut self) -> IterMut<'a, T> {
-        let mut iter = self.0.iter_mut();
-        let column: slice::IterMut<Point<T>> = match iter.next() {
-            Some(column) => column.iter_mut(),
-            None => [].iter_mut(),
-        };
-        IterMut {
-            rows: iter,
-            columns: column,
-        }
+        IterMut(self.storage.iter_mut())
+    }
+
+    /** Returns an iterator visiting every value together with its `(Row, Col)` location.
+    # Examples
+    ```
+    use symetrical_grid::{grid, Row, Col};
+
+    let grid = grid![[1, 2], [3, 4]];
+    let mut iter = grid.indexed_iter();
+    assert_eq!(iter.next(), Some(((Row(0), Col(0)), &1)));
+    assert_eq!(iter.next(), Some(((Row(0), Col(1)), &2)));
+    assert_eq!(iter.next(), Some(((Row(1), Col(0)), &3)));
+    assert_eq!(iter.next(), Some(((Row(1), Col(1)), &4)));
+    assert_eq!(iter.next(), None);
+    ```
+    */
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ((Row, Col), &T)> {
+        let cols = self.cols;
+        self.storage
+            .iter()
+            .enumerate()
+            .map(move |(i, point)| ((Row(i / cols), Col(i % cols)), &point.0))
+    }
+
+    /** Provides an iterator with mutable references, each paired with its `(Row, Col)` location.
+    # Examples
+    ```
+    use symetrical_grid::{grid, Row, Col};
+
+    let mut grid = grid![[1, 2], [3, 4]];
+    for (_, value) in grid.indexed_iter_mut() {
+        *value *= 10;
+    }
+    assert_eq!(grid.get_point(1, 1), Some(&40));
+    ```
+    */
+    pub fn indexed_iter_mut(&mut self) -> impl Iterator<Item = ((Row, Col), &mut T)> {
+        let cols = self.cols;
+        self.storage
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, point)| ((Row(i / cols), Col(i % cols)), &mut point.0))
     }
 }
 impl<T> X<T>
 where
     T: Default + Clone + Into<Point<T>>,
 {
-    /** Adds a new row to the grid.
+    /** Adds a new row to the grid, sized to the grid's current width.
     # Examples
     ```
     # fn main() {
@@ -137,10 +285,8 @@ where
     ```
     */
     pub fn add_row(&mut self) {
-        self.0.push(Y::new());
-        let grid_depth = self.0[0].len();
-        let len = self.0.len() - 1;
-        self.0[len].0.resize(grid_depth, T::default().into())
+        self.storage.resize(self.storage.len() + self.cols, T::default().into());
+        self.rows += 1;
     }
     /** Adds column to X.
     # Examples
@@ -155,12 +301,20 @@ where
     ```
     */
     pub fn add_column(&mut self) {
-        if self.0.is_empty() {
-            self.0.push(Y::new())
+        if self.rows == 0 {
+            self.rows = 1;
         }
-        for row in &mut self.0[..] {
-            row.0.push(T::default().into())
+        let new_cols = self.cols + 1;
+        let mut new_data = Vec::with_capacity(self.rows * new_cols);
+        for row in 0..self.rows {
+            let start = row * self.cols;
+            if self.cols > 0 {
+                new_data.extend_from_slice(&self.storage[start..start + self.cols]);
+            }
+            new_data.push(T::default().into());
         }
+        self.storage = new_data;
+        self.cols = new_cols;
     }
 
     /** Places `value` at `point`.
@@ -179,36 +333,37 @@ where
     ```
         */
     pub fn push_point(&mut self, x: usize, y: usize, value: T) {
-        if self.0.is_empty() {
-            self.0.push(Y::new());
-        }
-        if self.0[0].len() <= y {
-            for _ in self.0[0].len()..=y {
+        if self.cols <= y {
+            for _ in self.cols..=y {
                 self.add_column();
             }
         }
-        if self.0.len() <= x {
-            for _ in self.0.len()..=x {
+        if self.rows <= x {
+            for _ in self.rows..=x {
                 self.add_row();
             }
         }
-        self.0[x][y] = value.into();
+        let idx = self.to_index(x, y);
+        self.storage[idx] = value.into();
     }
     /** Adds a row to the grid.
+
+    Under the contiguous backing store every row shares the grid's width, so (unlike the old
+    per-row-`Vec` representation) this is now equivalent to [`X::add_row`]: there is no such
+    thing as an un-resized, ragged row anymore.
     # Examples
     ```
-    use symetrical_grid::{X, Y};
+    use symetrical_grid::X;
 
-    fn main() {
-        let mut grid: X<i32> = X::new();
-        grid.add_row();
-        assert_eq!(grid[0], Y::new());
-    }
+    let mut grid: X<i32> = X::new();
+    grid.add_row();
+    assert_eq!(grid.len(), 1);
+    assert!(grid[0].is_empty());
     ```
     */
     #[inline]
     pub fn add_row_no_resize(&mut self) {
-        self.0.push(Y::new())
+        self.add_row();
     }
 
     /** Pops the last row from the grid
@@ -223,15 +378,21 @@ where
     # }
     ```
     */
-    #[inline]
     pub fn pop_row(&mut self) -> Option<Y<T>> {
-        self.0.pop()
+        if self.rows == 0 {
+            return None;
+        }
+        let start = (self.rows - 1) * self.cols;
+        let row = self.storage.split_off(start);
+        self.rows -= 1;
+        Some(Y(row))
     }
 
     /// Resizes `X` in place so that `len` is equal to `new_len`.
     #[inline]
     pub fn resize(&mut self, new_len: usize) {
-        self.0.resize(new_len, Y::new());
+        self.storage.resize(new_len * self.cols, T::default().into());
+        self.rows = new_len;
     }
     /** Pushes `row` to the end of the grid
     # Examples
@@ -243,42 +404,586 @@ where
     assert_eq!(x[0][1], Point::from(2));
     ```
      */
-    #[inline]
     pub fn push_row(&mut self, row: Y<T>) {
-        self.0.push(row);
+        if self.rows == 0 {
+            self.cols = row.0.len();
+        } else {
+            assert_eq!(
+                row.0.len(),
+                self.cols,
+                "row length {} does not match grid width {}",
+                row.0.len(),
+                self.cols
+            );
+        }
+        self.storage.extend(row.0);
+        self.rows += 1;
     }
 
     pub fn with_capacity(capacity: usize) -> Self {
-        Self(Vec::with_capacity(capacity))
+        Self {
+            storage: Vec::with_capacity(capacity),
+            rows: 0,
+            cols: 0,
+            mode: PhantomData,
+        }
     }
     pub fn with_size(x: usize, y: usize) -> Self {
-        let mut y_vec = Y::with_capacity(y);
-        y_vec.resize(y, T::default());
-        let mut x_vec = X::with_capacity(x);
-        x_vec.0.resize(x, y_vec);
-        x_vec
+        Self {
+            storage: vec![T::default().into(); x * y],
+            rows: x,
+            cols: y,
+            mode: PhantomData,
+        }
+    }
+
+    /** Returns the in-bounds orthogonal (up/down/left/right) neighbors of `(x, y)`, clamped at the grid edges.
+    # Examples
+    ```
+    use symetrical_grid::grid;
+
+    let grid = grid![[1, 2], [3, 4]];
+    let neighbors: Vec<_> = grid.neighbors4(0, 0).collect();
+    assert_eq!(neighbors, vec![(1, 0), (0, 1)]);
+    ```
+    */
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        let rows = self.rows;
+        let cols = self.cols;
+        [
+            x.checked_sub(1).map(|x| (x, y)),
+            (x + 1 < rows).then_some((x + 1, y)),
+            y.checked_sub(1).map(|y| (x, y)),
+            (y + 1 < cols).then_some((x, y + 1)),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    /** Returns the in-bounds orthogonal and diagonal neighbors of `(x, y)`, clamped at the grid edges.
+    # Examples
+    ```
+    use symetrical_grid::grid;
+
+    let grid = grid![[1, 2], [3, 4]];
+    let neighbors: Vec<_> = grid.neighbors8(0, 0).collect();
+    assert_eq!(neighbors, vec![(0, 1), (1, 0), (1, 1)]);
+    ```
+    */
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        let rows = self.rows as isize;
+        let cols = self.cols as isize;
+        let x = x as isize;
+        let y = y as isize;
+        (-1..=1)
+            .flat_map(|dx| (-1..=1).map(move |dy| (dx, dy)))
+            .filter(|&(dx, dy)| (dx, dy) != (0, 0))
+            .filter_map(move |(dx, dy)| {
+                let (nx, ny) = (x + dx, y + dy);
+                if nx >= 0 && nx < rows && ny >= 0 && ny < cols {
+                    Some((nx as usize, ny as usize))
+                } else {
+                    None
+                }
+            })
+    }
+
+    /** Computes shortest unweighted-path distances from `start` over cells for which `passable` returns `true`.
+
+    Returns a grid the same size as `self` where each cell holds `Some(distance)` if reachable from
+    `start` through passable cells, or `None` otherwise. This is a standard breadth-first search: every
+    cell is enqueued at most once, so the returned distances are shortest-path lengths on the grid
+    treated as an unweighted graph connected via [`X::neighbors4`].
+    # Examples
+    ```
+    use symetrical_grid::grid;
+
+    let grid = grid![[1, 1, 1], [1, 0, 1], [1, 1, 1]];
+    let distances = grid.bfs_from((0, 0), |&cell| cell != 0);
+    assert_eq!(distances.get_point(0, 0), Some(&Some(0)));
+    assert_eq!(distances.get_point(1, 1), Some(&None));
+    assert_eq!(distances.get_point(2, 2), Some(&Some(4)));
+    ```
+    */
+    pub fn bfs_from(
+        &self,
+        start: (usize, usize),
+        passable: impl Fn(&T) -> bool,
+    ) -> X<Option<usize>> {
+        let mut distances = X::with_size(self.rows, self.cols);
+        let mut queue = std::collections::VecDeque::new();
+        distances.push_point(start.0, start.1, Some(0));
+        queue.push_back(start);
+        while let Some((x, y)) = queue.pop_front() {
+            let distance = distances.get_point(x, y).copied().flatten().unwrap();
+            for (nx, ny) in self.neighbors4(x, y) {
+                let is_passable = self.get_point(nx, ny).map(&passable).unwrap_or(false);
+                let is_unvisited = distances
+                    .get_point(nx, ny)
+                    .map(Option::is_none)
+                    .unwrap_or(false);
+                if is_passable && is_unvisited {
+                    distances.push_point(nx, ny, Some(distance + 1));
+                    queue.push_back((nx, ny));
+                }
+            }
+        }
+        distances
+    }
+
+    /** Produces a new grid by applying `rule` to every cell together with its in-bounds 8-neighbors.
+
+    `rule` only reads from `self`, so the whole grid advances one generation atomically: updates to
+    one cell never see another cell's already-updated value.
+    # Examples
+    ```
+    use symetrical_grid::grid;
+
+    // Conway's Game of Life.
+    let grid = grid![[0, 1, 0], [0, 1, 0], [0, 1, 0]];
+    let next = grid.step_with(|&cell, neighbors| {
+        let alive = neighbors.iter().filter(|&&(_, _, v)| *v == 1).count();
+        match (cell, alive) {
+            (1, 2) | (1, 3) => 1,
+            (0, 3) => 1,
+            _ => 0,
+        }
+    });
+    assert_eq!(next.get_point(1, 0), Some(&1));
+    assert_eq!(next.get_point(1, 1), Some(&1));
+    assert_eq!(next.get_point(1, 2), Some(&1));
+    assert_eq!(next.get_point(0, 1), Some(&0));
+    ```
+    */
+    pub fn step_with<F>(&self, rule: F) -> X<T>
+    where
+        F: Fn(&T, &[(usize, usize, &T)]) -> T,
+    {
+        let mut next = X::with_size(self.rows, self.cols);
+        for x in 0..self.rows {
+            for y in 0..self.cols {
+                let current = self.get_point(x, y).expect("(x, y) is in bounds");
+                let neighbors: Vec<(usize, usize, &T)> = self
+                    .neighbors8(x, y)
+                    .map(|(nx, ny)| {
+                        (
+                            nx,
+                            ny,
+                            self.get_point(nx, ny).expect("neighbor is in bounds"),
+                        )
+                    })
+                    .collect();
+                next.push_point(x, y, rule(current, &neighbors));
+            }
+        }
+        next
+    }
+
+    /** Advances `self` by one generation in place, double-buffering the result of [`X::step_with`].
+    # Examples
+    ```
+    use symetrical_grid::grid;
+
+    let mut grid = grid![[0, 1, 0], [0, 1, 0], [0, 1, 0]];
+    grid.step_synchronous(|&cell, neighbors| {
+        let alive = neighbors.iter().filter(|&&(_, _, v)| *v == 1).count();
+        match (cell, alive) {
+            (1, 2) | (1, 3) => 1,
+            (0, 3) => 1,
+            _ => 0,
+        }
+    });
+    assert_eq!(grid.get_point(1, 1), Some(&1));
+    ```
+    */
+    pub fn step_synchronous<F>(&mut self, rule: F)
+    where
+        F: Fn(&T, &[(usize, usize, &T)]) -> T,
+    {
+        *self = self.step_with(rule);
+    }
+}
+
+impl<T> X<T>
+where
+    T: Default + Clone + Into<Point<T>> + PartialEq,
+{
+    /** Repeatedly applies `rule` via [`X::step_with`] until the grid stops changing.
+
+    Returns the stabilized grid along with the number of generations it took to reach it.
+    # Examples
+    ```
+    use symetrical_grid::grid;
+
+    // A single live cell with no neighbors always dies after one generation.
+    let grid = grid![[0, 0, 0], [0, 1, 0], [0, 0, 0]];
+    let (stable, generations) = grid.step_until_stable(|&cell, neighbors| {
+        let alive = neighbors.iter().filter(|&&(_, _, v)| *v == 1).count();
+        match (cell, alive) {
+            (1, 2) | (1, 3) => 1,
+            (0, 3) => 1,
+            _ => 0,
+        }
+    });
+    assert_eq!(generations, 1);
+    assert_eq!(stable.get_point(1, 1), Some(&0));
+    ```
+    */
+    pub fn step_until_stable<F>(&self, rule: F) -> (X<T>, usize)
+    where
+        F: Fn(&T, &[(usize, usize, &T)]) -> T,
+    {
+        let mut current = self.clone();
+        let mut generations = 0;
+        loop {
+            let next = current.step_with(&rule);
+            if next == current {
+                return (next, generations);
+            }
+            current = next;
+            generations += 1;
+        }
     }
 }
+
+impl<T> X<T>
+where
+    T: Default + Clone + Into<Point<T>>,
+{
+    /** Applies `f` to every cell, producing a new grid of the results.
+    # Examples
+    ```
+    use symetrical_grid::grid;
+
+    let grid = grid![[1, 2], [3, 4]];
+    let doubled = grid.map(|&x| x * 2);
+    assert_eq!(doubled.get_point(1, 1), Some(&8));
+    ```
+    */
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> X<U>
+    where
+        U: Default + Clone + Into<Point<U>>,
+    {
+        X {
+            storage: self.storage.iter().map(|point| f(&point.0).into()).collect(),
+            rows: self.rows,
+            cols: self.cols,
+            mode: PhantomData,
+        }
+    }
+
+    /** Combines `self` and `other`, cell by cell, with `f`.
+    # Panics
+    Panics if `self` and `other` have different shapes.
+    # Examples
+    ```
+    use symetrical_grid::grid;
+
+    let a = grid![[1, 2], [3, 4]];
+    let b = grid![[10, 20], [30, 40]];
+    let sum = a.zip_with(&b, |x, y| x + y);
+    assert_eq!(sum.get_point(1, 1), Some(&44));
+    ```
+    */
+    pub fn zip_with<U, V>(&self, other: &X<U>, f: impl Fn(&T, &U) -> V) -> X<V>
+    where
+        U: Default + Clone + Into<Point<U>>,
+        V: Default + Clone + Into<Point<V>>,
+    {
+        assert_eq!(
+            (self.rows, self.cols),
+            (other.rows, other.cols),
+            "cannot combine a {}x{} grid with a {}x{} grid",
+            self.rows,
+            self.cols,
+            other.rows,
+            other.cols
+        );
+        X {
+            storage: self
+                .storage
+                .iter()
+                .zip(other.storage.iter())
+                .map(|(a, b)| f(&a.0, &b.0).into())
+                .collect(),
+            rows: self.rows,
+            cols: self.cols,
+            mode: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for X<T, Sparse>
+where
+    T: Default + Clone,
+{
+    fn default() -> Self {
+        Self {
+            storage: SparseStorage::new(),
+            rows: 0,
+            cols: 0,
+            mode: PhantomData,
+        }
+    }
+}
+
+impl<T> X<T, Sparse>
+where
+    T: Default + Clone,
+{
+    /** Returns the value at `point`, or `T::default()` if it is out of bounds or was never set.
+
+    # Examples
+    ```
+    use symetrical_grid::{X, Sparse};
+
+    let mut grid: X<u32, Sparse> = X::default();
+    grid.push_point(3, 4, 7);
+    assert_eq!(grid.get_point(3, 4), 7);
+    assert_eq!(grid.get_point(0, 0), 0);
+    assert_eq!(grid.get_point(100, 100), 0);
+    ```
+    */
+    pub fn get_point(&self, x: usize, y: usize) -> T {
+        if x >= self.rows || y >= self.cols {
+            return T::default();
+        }
+        self.storage.get(x, y).cloned().unwrap_or_default()
+    }
+
+    /** Places `value` at `point`, keeping each row's entries sorted by column.
+
+    X will be expanded to accommodate point location if necessary, same as the dense `Symetrical`
+    mode. Only `value`s that were actually pushed allocate storage; every other cell reads back as
+    `T::default()` via [`X::get_point`].
+    # Examples
+    ```
+    use symetrical_grid::{X, Sparse};
+
+    let mut grid: X<u32, Sparse> = X::default();
+    grid.push_point(3, 4, 5);
+    assert_eq!(grid.get_point(3, 4), 5);
+    grid.push_point(3, 4, 9);
+    assert_eq!(grid.get_point(3, 4), 9);
+    ```
+    */
+    pub fn push_point(&mut self, x: usize, y: usize, value: T) {
+        if y >= self.cols {
+            self.cols = y + 1;
+        }
+        if x >= self.rows {
+            for _ in self.rows..=x {
+                self.storage.push_row();
+            }
+            self.rows = x + 1;
+        }
+        self.storage.insert(x, y, value);
+    }
+}
+
+/** Combines two equally-shaped grids component-wise.
+# Panics
+Panics if the grids have different shapes.
+*/
+impl<T> Add for X<T>
+where
+    T: Default + Clone + Into<Point<T>> + Add<Output = T>,
+{
+    type Output = X<T>;
+    fn add(self, rhs: X<T>) -> X<T> {
+        self.zip_with(&rhs, |a, b| a.clone() + b.clone())
+    }
+}
+
+/// Broadcasts `rhs` to every cell in the grid.
+impl<T> Add<T> for X<T>
+where
+    T: Default + Clone + Into<Point<T>> + Add<Output = T>,
+{
+    type Output = X<T>;
+    fn add(self, rhs: T) -> X<T> {
+        self.map(|a| a.clone() + rhs.clone())
+    }
+}
+
+impl<T> AddAssign for X<T>
+where
+    T: Default + Clone + Into<Point<T>> + Add<Output = T>,
+{
+    fn add_assign(&mut self, rhs: X<T>) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl<T> AddAssign<T> for X<T>
+where
+    T: Default + Clone + Into<Point<T>> + Add<Output = T>,
+{
+    fn add_assign(&mut self, rhs: T) {
+        *self = self.clone() + rhs;
+    }
+}
+
+/** Combines two equally-shaped grids component-wise.
+# Panics
+Panics if the grids have different shapes.
+*/
+impl<T> Sub for X<T>
+where
+    T: Default + Clone + Into<Point<T>> + Sub<Output = T>,
+{
+    type Output = X<T>;
+    fn sub(self, rhs: X<T>) -> X<T> {
+        self.zip_with(&rhs, |a, b| a.clone() - b.clone())
+    }
+}
+
+/// Broadcasts `rhs` to every cell in the grid.
+impl<T> Sub<T> for X<T>
+where
+    T: Default + Clone + Into<Point<T>> + Sub<Output = T>,
+{
+    type Output = X<T>;
+    fn sub(self, rhs: T) -> X<T> {
+        self.map(|a| a.clone() - rhs.clone())
+    }
+}
+
+impl<T> SubAssign for X<T>
+where
+    T: Default + Clone + Into<Point<T>> + Sub<Output = T>,
+{
+    fn sub_assign(&mut self, rhs: X<T>) {
+        *self = self.clone() - rhs;
+    }
+}
+
+impl<T> SubAssign<T> for X<T>
+where
+    T: Default + Clone + Into<Point<T>> + Sub<Output = T>,
+{
+    fn sub_assign(&mut self, rhs: T) {
+        *self = self.clone() - rhs;
+    }
+}
+
+/** Combines two equally-shaped grids component-wise.
+# Panics
+Panics if the grids have different shapes.
+*/
+impl<T> Mul for X<T>
+where
+    T: Default + Clone + Into<Point<T>> + Mul<Output = T>,
+{
+    type Output = X<T>;
+    fn mul(self, rhs: X<T>) -> X<T> {
+        self.zip_with(&rhs, |a, b| a.clone() * b.clone())
+    }
+}
+
+/// Broadcasts `rhs` to every cell in the grid.
+impl<T> Mul<T> for X<T>
+where
+    T: Default + Clone + Into<Point<T>> + Mul<Output = T>,
+{
+    type Output = X<T>;
+    fn mul(self, rhs: T) -> X<T> {
+        self.map(|a| a.clone() * rhs.clone())
+    }
+}
+
+impl<T> MulAssign for X<T>
+where
+    T: Default + Clone + Into<Point<T>> + Mul<Output = T>,
+{
+    fn mul_assign(&mut self, rhs: X<T>) {
+        *self = self.clone() * rhs;
+    }
+}
+
+impl<T> MulAssign<T> for X<T>
+where
+    T: Default + Clone + Into<Point<T>> + Mul<Output = T>,
+{
+    fn mul_assign(&mut self, rhs: T) {
+        *self = self.clone() * rhs;
+    }
+}
+
+/** Combines two equally-shaped grids component-wise.
+# Panics
+Panics if the grids have different shapes.
+*/
+impl<T> Div for X<T>
+where
+    T: Default + Clone + Into<Point<T>> + Div<Output = T>,
+{
+    type Output = X<T>;
+    fn div(self, rhs: X<T>) -> X<T> {
+        self.zip_with(&rhs, |a, b| a.clone() / b.clone())
+    }
+}
+
+/// Broadcasts `rhs` to every cell in the grid.
+impl<T> Div<T> for X<T>
+where
+    T: Default + Clone + Into<Point<T>> + Div<Output = T>,
+{
+    type Output = X<T>;
+    fn div(self, rhs: T) -> X<T> {
+        self.map(|a| a.clone() / rhs.clone())
+    }
+}
+
+impl<T> DivAssign for X<T>
+where
+    T: Default + Clone + Into<Point<T>> + Div<Output = T>,
+{
+    fn div_assign(&mut self, rhs: X<T>) {
+        *self = self.clone() / rhs;
+    }
+}
+
+impl<T> DivAssign<T> for X<T>
+where
+    T: Default + Clone + Into<Point<T>> + Div<Output = T>,
+{
+    fn div_assign(&mut self, rhs: T) {
+        *self = self.clone() / rhs;
+    }
+}
+
 impl<A> FromIterator<Vec<A>> for X<A> {
     fn from_iter<T: IntoIterator<Item = Vec<A>>>(iter: T) -> Self {
-        Self(
-            iter.into_iter()
-                .map(|x| x.into_iter().collect::<Y<A>>())
-                .collect::<Vec<Y<A>>>(),
-        )
+        let mut data = Vec::new();
+        let mut rows = 0;
+        let mut cols = 0;
+        for row in iter {
+            if rows == 0 {
+                cols = row.len();
+            }
+            data.extend(row.into_iter().map(Point::from));
+            rows += 1;
+        }
+        Self {
+            storage: data,
+            rows,
+            cols,
+            mode: PhantomData,
+        }
     }
 }
 
 impl<T> Deref for X<T> {
-    type Target = [Y<T>];
+    type Target = [Point<T>];
     fn deref(&self) -> &Self::Target {
-        &self.0[..]
+        &self.storage[..]
     }
 }
 
 impl<T> DerefMut for X<T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0[..]
+        &mut self.storage[..]
     }
 }
 
@@ -287,7 +992,44 @@ where
     T: PartialEq,
 {
     fn eq(&self, other: &Self) -> bool {
-        self.0 == other.0
+        self.rows == other.rows && self.cols == other.cols && self.storage == other.storage
+    }
+}
+
+/// Indexes into a single row, returning the row's cells as a slice.
+impl<T> Index<usize> for X<T> {
+    type Output = [Point<T>];
+    fn index(&self, row: usize) -> &Self::Output {
+        &self.storage[row * self.cols..(row + 1) * self.cols]
+    }
+}
+
+impl<T> IndexMut<usize> for X<T> {
+    fn index_mut(&mut self, row: usize) -> &mut Self::Output {
+        let cols = self.cols;
+        &mut self.storage[row * cols..(row + 1) * cols]
+    }
+}
+
+/** Indexes into the grid with a strongly-typed `(Row, Col)` pair.
+# Examples
+```
+use symetrical_grid::{grid, Row, Col};
+
+let grid = grid![[1, 2, 3], [4, 5, 6]];
+assert_eq!(grid[(Row(1), Col(2))], 6);
+```
+*/
+impl<T> Index<(Row, Col)> for X<T> {
+    type Output = T;
+    fn index(&self, (row, col): (Row, Col)) -> &T {
+        &self[row.0][col.0].0
+    }
+}
+
+impl<T> IndexMut<(Row, Col)> for X<T> {
+    fn index_mut(&mut self, (row, col): (Row, Col)) -> &mut T {
+        &mut self[row.0][col.0].0
     }
 }
 
@@ -303,45 +1045,21 @@ where
         x
     }
 }
-/// An iterator over each item in each row.
-pub struct Iter<'a, T: 'a> {
-    rows: slice::Iter<'a, Y<T>>,
-    colums: slice::Iter<'a, Point<T>>,
-}
+/// An iterator over each item in the grid, in row-major order.
+pub struct Iter<'a, T: 'a>(slice::Iter<'a, Point<T>>);
 impl<'a, T: 'a> Iterator for Iter<'a, T> {
     type Item = &'a T;
     fn next(&mut self) -> Option<Self::Item> {
-        match self.colums.next() {
-            Some(n) => Some(n),
-            None => match self.rows.next() {
-                Some(n) => {
-                    self.colums = n.iter();
-                    self.next()
-                }
-                None => None,
-            },
-        }
+        self.0.next().map(|point| &point.0)
     }
 }
-/// A mutable iterator over each item in each row.
-pub struct IterMut<'a, T: 'a> {
-    rows: slice::IterMut<'a, Y<T>>,
-    columns: slice::IterMut<'a, Point<T>>,
-}
+/// A mutable iterator over each item in the grid, in row-major order.
+pub struct IterMut<'a, T: 'a>(slice::IterMut<'a, Point<T>>);
 
 impl<'a, T: 'a> Iterator for IterMut<'a, T> {
     type Item = &'a mut T;
     fn next(&mut self) -> Option<Self::Item> {
-        match self.columns.next() {
-            Some(n) => Some(n),
-            None => match self.rows.next() {
-                Some(n) => {
-                    self.columns = n.iter_mut();
-                    self.next()
-                }
-                None => None,
-            },
-        }
+        self.0.next().map(|point| &mut point.0)
     }
 }
 #[macro_export]
@@ -392,14 +1110,8 @@ mod test {
         let t = [&x[..], &y[..]];
         let x = X::from(&t[..]);
         let mut x_c = X::new();
-        x_c.add_row();
-        x_c.add_row();
-        x_c[0].push(1);
-        x_c[0].push(2);
-        x_c[0].push(3);
-        x_c[1].push(2);
-        x_c[1].push(3);
-        x_c[1].push(4);
+        x_c.push_row(Y::from(&[1, 2, 3][..]));
+        x_c.push_row(Y::from(&[2, 3, 4][..]));
         assert_eq!(x, x_c);
     }
 }
@@ -35,13 +35,13 @@ where
 
     # Examples
     ```
-    # use symetrical_grid::{X, Point, Y};
+    # use symetrical_grid::{Point, Y};
     # fn main() {
-        let mut grid = X::new();
-        grid.add_row_no_resize();
-        grid[0].push(3);
-        assert_eq!(grid[0][0], Point::from(3));
+        let mut row = Y::new();
+        row.push(3);
+        assert_eq!(row[0], Point::from(3));
     # }
+    ```
     */
     #[inline]
     pub fn push(&mut self, value: T) {